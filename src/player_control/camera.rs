@@ -1,7 +1,18 @@
 use crate::player_control::actions::{Actions, ActionsFrozen};
 use crate::player_control::camera::focus::set_camera_focus;
+use crate::player_control::camera::gltf_cameras::{
+    cycle_active_camera, prune_despawned_gltf_cameras, register_gltf_cameras, ActiveView,
+    GltfCameraRegistry,
+};
+use crate::player_control::camera::ui_target::{
+    apply_default_ui_camera_target, apply_ui_camera_target, propagate_ui_camera_target,
+    UiCameraTarget,
+};
+use crate::spawning::objects::skybox::{attach_skybox_once_loaded, SkyboxMaterial};
 use crate::util::trait_extension::{Vec2Ext, Vec3Ext};
 use crate::GameState;
+use bevy::input::mouse::MouseWheel;
+use bevy::pbr::MaterialPlugin;
 use bevy::prelude::*;
 use bevy::window::CursorGrabMode;
 use bevy_rapier3d::prelude::*;
@@ -9,8 +20,8 @@ use serde::{Deserialize, Serialize};
 use std::f32::consts::{PI, TAU};
 
 pub mod focus;
-
-const MAX_DISTANCE: f32 = 5.0;
+pub mod gltf_cameras;
+pub mod ui_target;
 
 pub struct CameraPlugin;
 
@@ -18,29 +29,149 @@ impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<UiCamera>()
             .register_type::<MainCamera>()
+            .register_type::<CameraConfig>()
+            .register_type::<AdjustableCameraSetting>()
+            .register_type::<UiCameraTarget>()
+            .init_resource::<CameraConfig>()
+            .init_resource::<AdjustableCameraSetting>()
+            .init_resource::<GltfCameraRegistry>()
+            .init_resource::<ActiveView>()
+            .add_plugin(MaterialPlugin::<SkyboxMaterial>::default())
             .add_startup_system(spawn_ui_camera)
             // Enables the system that synchronizes your `Transform`s and `LookTransform`s.
             .add_system_set(SystemSet::on_enter(GameState::Playing).with_system(despawn_ui_camera))
             .add_system_set(
                 SystemSet::on_update(GameState::Playing)
                     .with_system(follow_target.label("follow_target"))
+                    .with_system(
+                        cycle_camera_mode
+                            .label("cycle_camera_mode")
+                            .before("handle_camera_controls"),
+                    )
                     .with_system(
                         handle_camera_controls
                             .label("handle_camera_controls")
                             .after("follow_target"),
                     )
+                    .with_system(
+                        update_camera_sway
+                            .label("update_camera_sway")
+                            .after("handle_camera_controls"),
+                    )
                     .with_system(
                         update_camera_transform
                             .label("update_camera_transform")
-                            .after("handle_camera_controls"),
+                            .after("update_camera_sway"),
                     )
                     .with_system(cursor_grab_system)
+                    .with_system(cycle_adjustable_camera_setting)
+                    .with_system(adjust_camera_setting_with_scroll_wheel)
+                    .with_system(register_gltf_cameras)
+                    .with_system(prune_despawned_gltf_cameras.after(register_gltf_cameras))
+                    .with_system(
+                        cycle_active_camera
+                            .after(register_gltf_cameras)
+                            .after(prune_despawned_gltf_cameras),
+                    )
+                    .with_system(attach_skybox_once_loaded)
+                    .with_system(propagate_ui_camera_target)
+                    .with_system(apply_ui_camera_target.after(propagate_ui_camera_target))
+                    .with_system(apply_default_ui_camera_target.after(apply_ui_camera_target))
                     .with_system(init_camera_eye.before("follow_target"))
                     .with_system(set_camera_focus.before("follow_target")),
             );
     }
 }
 
+/// Runtime-tunable camera parameters. Edited in-game by cycling [`AdjustableCameraSetting`] with
+/// [`cycle_adjustable_camera_setting`] and scrolling with [`adjust_camera_setting_with_scroll_wheel`].
+#[derive(Debug, Clone, PartialEq, Resource, Reflect, Serialize, Deserialize)]
+#[reflect(Resource, Serialize, Deserialize)]
+pub struct CameraConfig {
+    pub mouse_sensitivity: f32,
+    /// How far the third-person eye orbits from its target before `keep_line_of_sight` pulls it
+    /// closer to avoid clipping through obstacles.
+    pub zoom_distance: f32,
+    pub free_fly_speed: f32,
+    pub translation_smoothing: f32,
+    pub rotation_smoothing: f32,
+    /// How far the procedural sway (see [`CameraSway`]) pushes the eye off its base position.
+    pub sway_amplitude: f32,
+    /// How quickly the sway decays back towards zero once movement/look input stops, in `1/s`.
+    pub sway_return_speed: f32,
+}
+
+impl Default for CameraConfig {
+    fn default() -> Self {
+        Self {
+            mouse_sensitivity: 1e-2,
+            zoom_distance: 5.0,
+            free_fly_speed: 5.0,
+            translation_smoothing: 10.,
+            rotation_smoothing: 15.,
+            sway_amplitude: 0.05,
+            sway_return_speed: 8.,
+        }
+    }
+}
+
+/// Which field of [`CameraConfig`] the scroll wheel currently edits.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Resource, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Resource, Serialize, Deserialize)]
+pub enum AdjustableCameraSetting {
+    #[default]
+    Zoom,
+    Sensitivity,
+    MovementSpeed,
+    Lerp,
+}
+
+impl AdjustableCameraSetting {
+    fn next(self) -> Self {
+        match self {
+            Self::MovementSpeed => Self::Zoom,
+            Self::Zoom => Self::Sensitivity,
+            Self::Sensitivity => Self::Lerp,
+            Self::Lerp => Self::MovementSpeed,
+        }
+    }
+}
+
+fn cycle_adjustable_camera_setting(
+    mut setting: ResMut<AdjustableCameraSetting>,
+    key: Res<Input<KeyCode>>,
+) {
+    if key.just_pressed(KeyCode::Tab) {
+        *setting = setting.next();
+    }
+}
+
+fn adjust_camera_setting_with_scroll_wheel(
+    mut scroll_events: EventReader<MouseWheel>,
+    setting: Res<AdjustableCameraSetting>,
+    mut config: ResMut<CameraConfig>,
+) {
+    let scroll: f32 = scroll_events.iter().map(|event| event.y).sum();
+    if scroll == 0. {
+        return;
+    }
+    match *setting {
+        AdjustableCameraSetting::MovementSpeed => {
+            config.free_fly_speed = (config.free_fly_speed + scroll).max(0.1)
+        }
+        AdjustableCameraSetting::Zoom => {
+            config.zoom_distance = (config.zoom_distance + scroll).max(0.1)
+        }
+        AdjustableCameraSetting::Sensitivity => {
+            config.mouse_sensitivity = (config.mouse_sensitivity + scroll * 1e-3).max(1e-4)
+        }
+        AdjustableCameraSetting::Lerp => {
+            config.translation_smoothing = (config.translation_smoothing + scroll).max(0.1);
+            config.rotation_smoothing = (config.rotation_smoothing + scroll).max(0.1);
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
 #[reflect(Component, Serialize, Deserialize)]
 pub struct UiCamera;
@@ -51,6 +182,8 @@ pub struct MainCamera {
     current: CameraPosition,
     new: CameraPosition,
     up: Vec3,
+    mode: CameraMode,
+    sway: CameraSway,
 }
 
 impl Default for MainCamera {
@@ -59,6 +192,8 @@ impl Default for MainCamera {
             current: default(),
             new: default(),
             up: Vec3::Y,
+            mode: default(),
+            sway: default(),
         }
     }
 }
@@ -77,6 +212,41 @@ impl MainCamera {
     pub fn forward(&self) -> Vec3 {
         self.new.eye.forward()
     }
+
+    pub fn mode(&self) -> CameraMode {
+        self.mode
+    }
+}
+
+/// The different ways the [`MainCamera`] can be driven. Cycled at runtime with
+/// [`cycle_camera_mode`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub enum CameraMode {
+    /// The eye is snapped directly onto the target, with zero orbit distance.
+    FirstPerson,
+    /// The default: the eye orbits the target at a distance, pulled closer to keep line of sight.
+    #[default]
+    ThirdPerson,
+    /// The eye is decoupled from the target and flies freely under WASD/mouse control.
+    FreeFly,
+    /// The eye is pinned directly above the target, looking straight down.
+    TopDown,
+}
+
+const TOP_DOWN_HEIGHT: f32 = 10.;
+
+fn cycle_camera_mode(mut camera_query: Query<&mut MainCamera>, key: Res<Input<KeyCode>>) {
+    if !key.just_pressed(KeyCode::V) {
+        return;
+    }
+    for mut camera in &mut camera_query {
+        camera.mode = match camera.mode {
+            CameraMode::FirstPerson => CameraMode::ThirdPerson,
+            CameraMode::ThirdPerson => CameraMode::FreeFly,
+            CameraMode::FreeFly => CameraMode::TopDown,
+            CameraMode::TopDown => CameraMode::FirstPerson,
+        };
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
@@ -86,6 +256,49 @@ pub struct CameraPosition {
     pub target: Vec3,
 }
 
+/// A small, smoothed positional and rotational offset applied to the eye in
+/// [`update_camera_transform`] to give the camera a handheld feel, akin to weapon sway. Tracked
+/// separately from [`CameraPosition`] so it never feeds back into `MainCamera::new`.
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub struct CameraSway {
+    translation: Vec2,
+    rotation: Quat,
+}
+
+fn update_camera_sway(
+    time: Res<Time>,
+    actions: Res<Actions>,
+    config: Res<CameraConfig>,
+    mut camera_query: Query<&mut MainCamera>,
+) {
+    let dt = time.delta_seconds();
+    // Scale by `mouse_sensitivity` first, just like `handle_camera_controls` does, so sway tracks
+    // how far the mouse actually moved instead of the raw, unscaled look delta (which is large
+    // enough to saturate the clamp below on virtually every frame with mouse input).
+    let look_delta = actions.camera_movement.unwrap_or_default() * config.mouse_sensitivity;
+    let velocity = actions.player_movement.unwrap_or_default();
+
+    let target_translation = (-look_delta * config.sway_amplitude)
+        .clamp_length_max(config.sway_amplitude)
+        + Vec2::new(velocity.x, -velocity.z) * config.sway_amplitude * 0.5;
+    let target_rotation = Quat::from_euler(
+        EulerRot::XYZ,
+        velocity.z * config.sway_amplitude * 0.5,
+        0.,
+        -look_delta.x * config.sway_amplitude,
+    );
+
+    // Fixing the "snaps back too quickly" problem: a frame-rate independent exponential decay
+    // rather than a constant lerp factor, so `sway_return_speed` behaves the same at any `dt`.
+    let smoothing = 1. - (-config.sway_return_speed * dt).exp();
+    for mut camera in &mut camera_query {
+        camera.sway.translation = camera.sway.translation.lerp(target_translation, smoothing);
+        camera.sway.rotation = camera.sway.rotation.slerp(target_rotation, smoothing);
+    }
+}
+
+/// Spawns the fallback 2D camera UI renders onto when a UI root has no explicit
+/// [`UiCameraTarget`] routing it to the `MainCamera` or another camera/viewport instead.
 fn spawn_ui_camera(mut commands: Commands) {
     commands.spawn((Camera2dBundle::default(), UiCamera, Name::new("Camera")));
 }
@@ -115,32 +328,77 @@ fn follow_target(mut camera_query: Query<&mut MainCamera>) {
     }
 }
 
-fn handle_camera_controls(mut camera_query: Query<&mut MainCamera>, actions: Res<Actions>) {
-    let mouse_sensitivity = 1e-2;
-    let camera_movement = match actions.camera_movement {
-        Some(vector) => vector * mouse_sensitivity,
-        None => return,
-    };
-
-    if camera_movement.is_approx_zero() {
-        return;
-    }
+fn handle_camera_controls(
+    mut camera_query: Query<&mut MainCamera>,
+    actions: Res<Actions>,
+    time: Res<Time>,
+    config: Res<CameraConfig>,
+) {
+    let camera_movement = actions
+        .camera_movement
+        .map(|vector| vector * config.mouse_sensitivity)
+        .unwrap_or_default();
+    let dt = time.delta_seconds();
 
     for mut camera in camera_query.iter_mut() {
-        let yaw = -camera_movement.x.clamp(-PI, PI);
-        let yaw_rotation = Quat::from_axis_angle(camera.up, yaw);
+        if matches!(camera.mode, CameraMode::TopDown) {
+            let eye = camera.up * TOP_DOWN_HEIGHT + camera.new.target;
+            camera.new.eye.translation = eye;
+            // `look_at` can't be used here: its forward would be parallel to `up`, which yields a
+            // degenerate rotation. Point the eye's forward (-Z) straight down instead.
+            camera.new.eye.rotation = Quat::from_rotation_arc(Vec3::NEG_Z, -camera.up);
+            continue;
+        }
 
-        let pitch = -camera_movement.y;
-        let pitch = clamp_pitch(&camera, pitch);
-        let pitch_rotation = Quat::from_axis_angle(camera.new.eye.local_x(), pitch);
+        if !camera_movement.is_approx_zero() {
+            let yaw = -camera_movement.x.clamp(-PI, PI);
+            let yaw_rotation = Quat::from_axis_angle(camera.up, yaw);
+
+            let pitch = -camera_movement.y;
+            let pitch = clamp_pitch(&camera, pitch);
+            let pitch_rotation = Quat::from_axis_angle(camera.new.eye.local_x(), pitch);
+            let rotation = yaw_rotation * pitch_rotation;
+
+            let pivot = match camera.mode {
+                // First-person and free-fly look around the eye itself rather than orbiting a
+                // pivot out at the target.
+                CameraMode::FirstPerson | CameraMode::FreeFly => camera.new.eye.translation,
+                CameraMode::ThirdPerson => camera.new.target,
+                CameraMode::TopDown => unreachable!(),
+            };
+            camera.new.eye.rotate_around(pivot, rotation);
+        }
+
+        if matches!(camera.mode, CameraMode::FreeFly) {
+            let forward = camera.new.eye.forward();
+            let right = camera.new.eye.right();
+            let movement = actions.player_movement.unwrap_or_default();
+            let translation = (forward * movement.z + right * movement.x + camera.up * movement.y)
+                * config.free_fly_speed
+                * dt;
+            camera.new.eye.translation += translation;
+            camera.new.target = camera.new.eye.translation;
+        }
 
-        let pivot = camera.new.target;
-        let rotation = yaw_rotation * pitch_rotation;
-        camera.new.eye.rotate_around(pivot, rotation);
+        if matches!(camera.mode, CameraMode::FirstPerson) {
+            // Keep the eye and target coincident every frame, not just on frames with mouse
+            // input: `follow_target` runs before this system and re-derives `eye`'s rotation via
+            // `look_at(target, ..)` whenever the two aren't coincident, which would otherwise
+            // stomp the incremental `rotate_around` above on the very next frame with zero mouse
+            // delta.
+            camera.new.target = camera.new.eye.translation;
+        }
     }
 }
 
 fn clamp_pitch(camera: &MainCamera, angle: f32) -> f32 {
+    // First-person and free-fly look directly along the eye's own forward axis, so they can look
+    // straight up/down without the acute-angle guard that keeps the orbit cameras from flipping
+    // over their pivot.
+    if matches!(camera.mode, CameraMode::FirstPerson | CameraMode::FreeFly) {
+        return angle;
+    }
+
     const MOST_ACUTE_ALLOWED_FROM_ABOVE: f32 = TAU / 10.;
     const MOST_ACUTE_ALLOWED_FROM_BELOW: f32 = TAU / 7.;
 
@@ -168,34 +426,62 @@ fn update_camera_transform(
     time: Res<Time>,
     mut camera_query: Query<(&mut Transform, &mut MainCamera)>,
     rapier_context: Res<RapierContext>,
+    config: Res<CameraConfig>,
 ) {
     let dt = time.delta_seconds();
     for (mut transform, mut camera) in camera_query.iter_mut() {
-        let line_of_sight_result = keep_line_of_sight(&camera, &rapier_context);
-        let translation_smoothing =
-            if line_of_sight_result.correction == LineOfSightCorrection::Closer {
-                25.
-            } else {
-                10.
-            };
-        let direction = line_of_sight_result.location - transform.translation;
-        let scale = (translation_smoothing * dt).max(1.);
+        // First-person snaps the eye directly onto the target with zero orbit distance, and
+        // free-fly/top-down have already placed the eye exactly where it should be, so none of
+        // them need the third-person raycast that pulls the eye closer to preserve line of sight.
+        let (target_location, translation_smoothing) = match camera.mode {
+            CameraMode::FirstPerson => (camera.new.target, config.translation_smoothing),
+            CameraMode::ThirdPerson => {
+                let line_of_sight_result = keep_line_of_sight(&camera, &rapier_context, &config);
+                let smoothing = if line_of_sight_result.correction == LineOfSightCorrection::Closer
+                {
+                    config.translation_smoothing * 2.5
+                } else {
+                    config.translation_smoothing
+                };
+                (line_of_sight_result.location, smoothing)
+            }
+            CameraMode::FreeFly | CameraMode::TopDown => {
+                (camera.new.eye.translation, config.translation_smoothing)
+            }
+        };
+        let direction = target_location - transform.translation;
+        // Capped at 1 rather than floored at 1: `scale` is the fraction of the remaining distance
+        // to close this frame, so it must never exceed 1 (which would overshoot), but anything
+        // below that should still interpolate instead of being forced to a full, instant snap -
+        // otherwise the "Lerp" setting has no visible effect anywhere under ~1/dt.
+        let scale = (translation_smoothing * dt).min(1.);
         transform.translation += direction * scale;
 
-        let rotation_smoothing = 15.;
-        let scale = (rotation_smoothing * dt).max(1.);
+        let scale = (config.rotation_smoothing * dt).min(1.);
         transform.rotation = transform.rotation.slerp(camera.new.eye.rotation, scale);
 
         camera.current = camera.new.clone();
+
+        // Sway is purely cosmetic, so it's layered onto the transform after everything else has
+        // settled rather than folded into `camera.current`/`camera.new`.
+        let sway = &camera.sway;
+        transform.translation +=
+            transform.right() * sway.translation.x + transform.up() * sway.translation.y;
+        transform.rotation *= sway.rotation;
     }
 }
 
-fn keep_line_of_sight(camera: &MainCamera, rapier_context: &RapierContext) -> LineOfSightResult {
+fn keep_line_of_sight(
+    camera: &MainCamera,
+    rapier_context: &RapierContext,
+    config: &CameraConfig,
+) -> LineOfSightResult {
     let origin = camera.new.target;
     let desired_direction = camera.new.eye.translation - camera.new.target;
     let norm_direction = desired_direction.try_normalize().unwrap_or(Vec3::Z);
 
-    let distance = get_raycast_distance(origin, norm_direction, rapier_context, MAX_DISTANCE);
+    let distance =
+        get_raycast_distance(origin, norm_direction, rapier_context, config.zoom_distance);
     let location = origin + norm_direction * distance;
     let correction = if distance * distance < desired_direction.length_squared() {
         LineOfSightCorrection::Closer
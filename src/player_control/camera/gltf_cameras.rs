@@ -0,0 +1,105 @@
+use crate::player_control::camera::MainCamera;
+use bevy::prelude::*;
+
+/// Cameras authored inside a loaded glTF scene (e.g. a fixed cinematic view placed in Blender),
+/// in the order they were spawned. Cycled together with the default [`MainCamera`] view by
+/// [`cycle_active_camera`].
+#[derive(Debug, Clone, Resource, Default)]
+pub struct GltfCameraRegistry {
+    cameras: Vec<Entity>,
+}
+
+/// Which camera is currently rendering: the gameplay [`MainCamera`], or one of the
+/// [`GltfCameraRegistry`] entries by index.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Resource, Default)]
+pub enum ActiveView {
+    #[default]
+    MainCamera,
+    Gltf(usize),
+}
+
+/// Picks up cameras that were just spawned as part of a glTF scene and adds them to the
+/// [`GltfCameraRegistry`], deactivated until cycled to.
+pub fn register_gltf_cameras(
+    mut registry: ResMut<GltfCameraRegistry>,
+    mut new_cameras: Query<(Entity, &mut Camera), (Added<Camera3d>, Without<MainCamera>)>,
+) {
+    for (entity, mut camera) in &mut new_cameras {
+        camera.is_active = false;
+        registry.cameras.push(entity);
+    }
+}
+
+/// Drops entries whose entity was despawned (e.g. on scene reload), so [`ActiveView::Gltf`]
+/// indices never point at a dead entity. Falls back to [`ActiveView::MainCamera`] if the view
+/// that was just pruned away happened to be active, since otherwise no camera would be active
+/// until `C` was pressed enough times to cycle back around.
+pub fn prune_despawned_gltf_cameras(
+    mut registry: ResMut<GltfCameraRegistry>,
+    mut active_view: ResMut<ActiveView>,
+    mut removed: RemovedComponents<Camera3d>,
+    mut main_camera_query: Query<&mut Camera, With<MainCamera>>,
+) {
+    let removed: Vec<Entity> = removed.iter().collect();
+    if removed.is_empty() {
+        return;
+    }
+
+    let active_entity = match *active_view {
+        ActiveView::Gltf(index) => registry.cameras.get(index).copied(),
+        ActiveView::MainCamera => None,
+    };
+    let active_was_removed = active_entity.is_some_and(|entity| removed.contains(&entity));
+
+    registry.cameras.retain(|entity| !removed.contains(entity));
+
+    if active_was_removed {
+        *active_view = ActiveView::MainCamera;
+        for mut camera in &mut main_camera_query {
+            camera.is_active = true;
+        }
+    } else if let Some(entity) = active_entity {
+        // Re-resolve the index, since removing earlier entries shifts the indices after them.
+        *active_view = match registry.cameras.iter().position(|&e| e == entity) {
+            Some(new_index) => ActiveView::Gltf(new_index),
+            None => ActiveView::MainCamera,
+        };
+    }
+}
+
+pub fn cycle_active_camera(
+    key: Res<Input<KeyCode>>,
+    mut active_view: ResMut<ActiveView>,
+    registry: Res<GltfCameraRegistry>,
+    mut main_camera_query: Query<&mut Camera, With<MainCamera>>,
+    mut gltf_camera_query: Query<&mut Camera, Without<MainCamera>>,
+) {
+    if !key.just_pressed(KeyCode::C) {
+        return;
+    }
+    *active_view = match *active_view {
+        ActiveView::MainCamera if registry.cameras.is_empty() => ActiveView::MainCamera,
+        ActiveView::MainCamera => ActiveView::Gltf(0),
+        ActiveView::Gltf(index) if index + 1 < registry.cameras.len() => {
+            ActiveView::Gltf(index + 1)
+        }
+        ActiveView::Gltf(_) => ActiveView::MainCamera,
+    };
+
+    // A pruned registry can shrink out from under a stale index on the very frame it's read here;
+    // treat that the same as cycling back to the main camera rather than deactivating it for a
+    // view that no longer resolves to a live entity.
+    let resolved_view = match *active_view {
+        ActiveView::Gltf(index) if index >= registry.cameras.len() => ActiveView::MainCamera,
+        other => other,
+    };
+
+    for mut camera in &mut main_camera_query {
+        camera.is_active = matches!(resolved_view, ActiveView::MainCamera);
+    }
+    for (index, &entity) in registry.cameras.iter().enumerate() {
+        if let Ok(mut camera) = gltf_camera_query.get_mut(entity) {
+            camera.is_active = matches!(resolved_view, ActiveView::Gltf(active_index) if active_index == index);
+        }
+    }
+}
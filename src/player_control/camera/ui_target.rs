@@ -0,0 +1,106 @@
+use bevy::ecs::query::QuerySingleError;
+use bevy::prelude::*;
+use bevy::ui::UiCameraConfig;
+use std::collections::HashSet;
+
+/// Tags a UI root node (or any of its descendants) with the camera its subtree should render to.
+/// Set this on the root passed to `MenuPlugin`/a HUD to route it onto a specific [`Camera`]
+/// entity, instead of requiring the dedicated `UiCamera`.
+///
+/// Bevy at this version doesn't support per-subtree UI render targets yet (that's
+/// `bevy::ui::TargetCamera`, which lands well after the `Windows`-resource/`SystemSet::on_update`
+/// era the rest of this plugin is pinned to). The finest-grained equivalent available is toggling
+/// [`UiCameraConfig::show_ui`] per camera, so [`apply_ui_camera_target`] uses that instead: every
+/// camera referenced by a [`UiCameraTarget`] anywhere in the UI tree gets UI enabled, every other
+/// camera gets it disabled.
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[reflect(Component)]
+pub struct UiCameraTarget(pub Entity);
+
+/// Copies an ancestor's [`UiCameraTarget`] onto children that don't have one of their own, so
+/// setting it once on a UI root routes its whole subtree.
+pub fn propagate_ui_camera_target(
+    mut commands: Commands,
+    roots: Query<(Entity, &UiCameraTarget)>,
+    children_query: Query<&Children>,
+    has_target: Query<&UiCameraTarget>,
+) {
+    for (root, target) in &roots {
+        let mut stack: Vec<Entity> = children_query
+            .get(root)
+            .map(|children| children.iter().copied().collect())
+            .unwrap_or_default();
+        while let Some(entity) = stack.pop() {
+            if has_target.get(entity).is_err() {
+                commands.entity(entity).insert(*target);
+            }
+            if let Ok(children) = children_query.get(entity) {
+                stack.extend(children.iter().copied());
+            }
+        }
+    }
+}
+
+/// The actual sink: enables [`UiCameraConfig::show_ui`] on every camera referenced by a
+/// [`UiCameraTarget`] and disables it everywhere else, since this Bevy version renders UI as a
+/// single overlay keyed off that flag rather than per-node-subtree camera routing.
+pub fn apply_ui_camera_target(
+    mut commands: Commands,
+    targeted: Query<&UiCameraTarget>,
+    cameras: Query<Entity, With<Camera>>,
+) {
+    if targeted.is_empty() {
+        return;
+    }
+
+    let targets: HashSet<Entity> = targeted.iter().map(|target| target.0).collect();
+    for camera in &cameras {
+        commands.entity(camera).insert(UiCameraConfig {
+            show_ui: targets.contains(&camera),
+        });
+    }
+}
+
+/// Falls back to the single existing camera when no UI root has an explicit [`UiCameraTarget`],
+/// and warns instead of silently guessing when more than one camera is present. The warning is
+/// only logged on the transition into the ambiguous state, not every frame it persists.
+pub fn apply_default_ui_camera_target(
+    mut commands: Commands,
+    untargeted_roots: Query<Entity, (With<Node>, Without<Parent>, Without<UiCameraTarget>)>,
+    any_target: Query<&UiCameraTarget>,
+    cameras: Query<Entity, With<Camera>>,
+    mut already_warned: Local<bool>,
+) {
+    // Explicit targeting already drove `apply_ui_camera_target` above; don't fight it over which
+    // camera has `show_ui` set.
+    if !any_target.is_empty() {
+        *already_warned = false;
+        return;
+    }
+
+    let untargeted_root_count = untargeted_roots.iter().count();
+    if untargeted_root_count == 0 {
+        *already_warned = false;
+        return;
+    }
+
+    match cameras.get_single() {
+        Ok(camera) => {
+            commands
+                .entity(camera)
+                .insert(UiCameraConfig { show_ui: true });
+            *already_warned = false;
+        }
+        Err(QuerySingleError::NoEntities(_)) => *already_warned = false,
+        Err(QuerySingleError::MultipleEntities(_)) => {
+            if !*already_warned {
+                warn!(
+                    "{untargeted_root_count} UI root(s) have no explicit UiCameraTarget, but \
+                     multiple cameras are present. Set UiCameraTarget explicitly to avoid \
+                     ambiguous rendering."
+                );
+                *already_warned = true;
+            }
+        }
+    }
+}
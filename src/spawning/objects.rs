@@ -7,6 +7,7 @@ pub mod primitives;
 pub mod roof;
 pub mod roof_left;
 pub mod roof_right;
+pub mod skybox;
 pub mod sunlight;
 pub mod util;
 pub mod wall;
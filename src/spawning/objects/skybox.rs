@@ -0,0 +1,119 @@
+use crate::player_control::camera::MainCamera;
+use crate::spawning::{GameObject, PrimedGameObjectSpawner};
+use bevy::asset::LoadState;
+use bevy::pbr::{
+    Material, MaterialPipeline, MaterialPipelineKey, NotShadowCaster, NotShadowReceiver,
+};
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+use bevy::render::mesh::MeshVertexBufferLayout;
+use bevy::render::render_resource::{
+    AsBindGroup, Face, RenderPipelineDescriptor, ShaderRef, SpecializedMeshPipelineError,
+    TextureViewDescriptor, TextureViewDimension,
+};
+
+pub const DEFAULT_CUBEMAP_PATH: &str = "environment_maps/sky.png";
+const SKYBOX_SHADER_PATH: &str = "shaders/skybox.wgsl";
+// Large enough to contain every other level object, small enough to stay inside the default
+// camera far plane.
+const SKYBOX_SCALE: f32 = 500.;
+
+impl<'w, 's, 'a, 'b> PrimedGameObjectSpawner<'w, 's, 'a, 'b> {
+    pub fn spawn_skybox(&'a mut self, cubemap_path: &str) {
+        let cubemap = self.asset_server.load(cubemap_path);
+        self.commands.spawn((
+            GameObject::Skybox,
+            SkyboxCubemap(cubemap),
+            Name::new("Skybox"),
+        ));
+    }
+}
+
+/// Marks an entity as wanting its cubemap image attached to the [`MainCamera`] as a
+/// [`SkyboxMaterial`]-rendered mesh once the bytes have actually loaded, since reinterpreting the
+/// image as [`TextureViewDimension::Cube`] requires the pixel data to already be present.
+#[derive(Debug, Clone, Component)]
+pub struct SkyboxCubemap(pub Handle<Image>);
+
+/// Samples a cubemap in every direction from the camera, giving the level a horizon/backdrop.
+/// Bevy doesn't gain a native `Skybox` camera component until well after the version this crate
+/// is pinned to, so this renders the cubemap onto a large cube mesh parented to `MainCamera`
+/// instead - the pre-built-in-support equivalent.
+#[derive(AsBindGroup, TypeUuid, Debug, Clone)]
+#[uuid = "a9f9f6a0-9b3a-4f7e-9c7f-2f9b1e6d6c2a"]
+pub struct SkyboxMaterial {
+    #[texture(0, dimension = "cube")]
+    #[sampler(1)]
+    pub cubemap: Handle<Image>,
+}
+
+impl Material for SkyboxMaterial {
+    fn fragment_shader() -> ShaderRef {
+        SKYBOX_SHADER_PATH.into()
+    }
+
+    fn specialize(
+        _pipeline: &MaterialPipeline<Self>,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayout,
+        _key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        // The camera sits inside the cube, so render its inner faces rather than culling them.
+        descriptor.primitive.cull_mode = Some(Face::Front);
+        Ok(())
+    }
+}
+
+pub fn attach_skybox_once_loaded(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<SkyboxMaterial>>,
+    skybox_query: Query<(Entity, &SkyboxCubemap)>,
+    main_camera_query: Query<Entity, With<MainCamera>>,
+) {
+    for (skybox_entity, cubemap) in &skybox_query {
+        // `MainCamera` may not have spawned yet when the cubemap finishes loading, since asset
+        // loads race scene spawning. Keep the marker around and retry next frame rather than
+        // discarding the skybox.
+        if main_camera_query.is_empty() {
+            continue;
+        }
+        if asset_server.get_load_state(&cubemap.0) != LoadState::Loaded {
+            continue;
+        }
+        let Some(image) = images.get_mut(&cubemap.0) else {
+            continue;
+        };
+        if image.texture_descriptor.array_layer_count() == 1 {
+            image.reinterpret_stacked_2d_as_array(image.height() / image.width());
+        }
+        image.texture_view_descriptor = Some(TextureViewDescriptor {
+            dimension: Some(TextureViewDimension::Cube),
+            ..default()
+        });
+
+        let mesh = meshes.add(Mesh::from(shape::Cube { size: 1. }));
+        let material = materials.add(SkyboxMaterial {
+            cubemap: cubemap.0.clone(),
+        });
+        for camera_entity in &main_camera_query {
+            let skybox_mesh = commands
+                .spawn((
+                    MaterialMeshBundle {
+                        mesh: mesh.clone(),
+                        material: material.clone(),
+                        transform: Transform::from_scale(Vec3::splat(SKYBOX_SCALE)),
+                        ..default()
+                    },
+                    NotShadowCaster,
+                    NotShadowReceiver,
+                    Name::new("Skybox Mesh"),
+                ))
+                .id();
+            commands.entity(camera_entity).add_child(skybox_mesh);
+        }
+        commands.entity(skybox_entity).despawn();
+    }
+}